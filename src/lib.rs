@@ -4,8 +4,10 @@
 //! n homogenous elements.
 //!
 //! ## Crate Features
-//! - `std`: Enables dependence on `std` to allow for more features
-//! - `serde`: Enables serializing/deserializing the `UnorderedNTuple` struct in serde
+//! - `std`: Pulls in `std` for the test suite's hashing checks. `Hash` itself is implemented via
+//!   `core::hash` and works without this feature.
+//! - `serde`: Enables serializing/deserializing the `UnorderedNTuple` struct in serde. This only
+//!   depends on `alloc`, not `std`, so it still works in `no_std` binaries that have an allocator.
 //!
 //! By default, both features are enabled.
 
@@ -16,12 +18,14 @@ macro_rules! if_feature {
 }
 
 #[rustfmt::skip]
-if_feature!("std", extern crate std; use std::hash::{Hash, Hasher};);
+if_feature!("std", extern crate std;);
 
 #[rustfmt::skip]
 if_feature!(
     "serde",
-    use std::{convert::TryInto, marker::PhantomData, fmt, vec::Vec};
+    extern crate alloc;
+    use alloc::vec::Vec;
+    use core::{convert::TryInto, marker::PhantomData, fmt};
     use serde::{
         de::{Deserialize, Deserializer, Error, SeqAccess, Visitor},
         ser::{Serialize, Serializer, SerializeSeq},
@@ -31,22 +35,64 @@ if_feature!(
 /// An `UnorderedPair` is a special subtype of `UnorderedNTuple` for only 2 elements. This has been
 /// given its own type for ease of use.
 ///
-/// It can also be converted to or from a tuple (similar impls for larger types will come once
-/// generics become stronger).
+/// It can also be converted to or from a tuple, as can any `UnorderedNTuple` of arity 1 through
+/// 12 (see the `From`/`Into` impls generated by `impl_unordered_tuple_conversion`).
 pub type UnorderedPair<T> = UnorderedNTuple<T, 2>;
 
-impl<T> From<(T, T)> for UnorderedPair<T> {
-    fn from(tuple: (T, T)) -> Self {
-        Self([tuple.0, tuple.1])
-    }
+/// Expands a bound tuple-position identifier into the (always homogeneous) element type `T`.
+///
+/// This only exists so that [`impl_unordered_tuple_conversion`] can drive a `T, T, ..., T` type
+/// list off of the same `$var` repetition used to build the value-level tuple.
+macro_rules! tuple_position_type {
+    ($var:ident) => {
+        T
+    };
 }
-impl<T> From<UnorderedPair<T>> for (T, T) {
-    fn from(pair: UnorderedPair<T>) -> (T, T) {
-        let [first, second] = pair.0;
-        (first, second)
-    }
+
+/// Generates `From` impls converting between `UnorderedNTuple<T, N>` and the homogeneous tuple
+/// `(T, T, ..., T)` of the same arity `N`.
+macro_rules! impl_unordered_tuple_conversion {
+    ($n:literal; $($idx:tt => $var:ident),+ $(,)?) => {
+        impl<T> From<($(tuple_position_type!($var)),+,)> for UnorderedNTuple<T, $n> {
+            fn from(tuple: ($(tuple_position_type!($var)),+,)) -> Self {
+                Self([$(tuple.$idx),+])
+            }
+        }
+
+        impl<T> From<UnorderedNTuple<T, $n>> for ($(tuple_position_type!($var)),+,) {
+            fn from(tuple: UnorderedNTuple<T, $n>) -> Self {
+                let [$($var),+] = tuple.0;
+                ($($var,)+)
+            }
+        }
+    };
 }
 
+impl_unordered_tuple_conversion!(1; 0 => a);
+impl_unordered_tuple_conversion!(2; 0 => a, 1 => b);
+impl_unordered_tuple_conversion!(3; 0 => a, 1 => b, 2 => c);
+impl_unordered_tuple_conversion!(4; 0 => a, 1 => b, 2 => c, 3 => d);
+impl_unordered_tuple_conversion!(5; 0 => a, 1 => b, 2 => c, 3 => d, 4 => e);
+impl_unordered_tuple_conversion!(6; 0 => a, 1 => b, 2 => c, 3 => d, 4 => e, 5 => f);
+impl_unordered_tuple_conversion!(7; 0 => a, 1 => b, 2 => c, 3 => d, 4 => e, 5 => f, 6 => g);
+impl_unordered_tuple_conversion!(
+    8; 0 => a, 1 => b, 2 => c, 3 => d, 4 => e, 5 => f, 6 => g, 7 => h
+);
+impl_unordered_tuple_conversion!(
+    9; 0 => a, 1 => b, 2 => c, 3 => d, 4 => e, 5 => f, 6 => g, 7 => h, 8 => i
+);
+impl_unordered_tuple_conversion!(
+    10; 0 => a, 1 => b, 2 => c, 3 => d, 4 => e, 5 => f, 6 => g, 7 => h, 8 => i, 9 => j
+);
+impl_unordered_tuple_conversion!(
+    11; 0 => a, 1 => b, 2 => c, 3 => d, 4 => e, 5 => f, 6 => g, 7 => h, 8 => i, 9 => j, 10 => k
+);
+impl_unordered_tuple_conversion!(
+    12;
+    0 => a, 1 => b, 2 => c, 3 => d, 4 => e, 5 => f, 6 => g, 7 => h, 8 => i, 9 => j, 10 => k,
+    11 => l
+);
+
 /// A type which represents an unordered tuple of N elements (i.e. an unordered pair if N == 2, and
 /// unordered triplet if N == 3, and so on).
 ///
@@ -71,6 +117,130 @@ impl<T, const N: usize> From<UnorderedNTuple<T, N>> for [T; N] {
     }
 }
 
+impl<T, const N: usize> UnorderedNTuple<T, N>
+where
+    T: Ord,
+{
+    /// Consumes this tuple and returns its elements in ascending sorted order.
+    ///
+    /// This is the canonical representation of an `UnorderedNTuple`: two tuples are equal (by
+    /// multiset identity) if and only if their sorted arrays are equal.
+    pub fn into_sorted(self) -> [T; N] {
+        let mut array = self.0;
+        array.sort_unstable();
+        array
+    }
+}
+
+impl<T, const N: usize> UnorderedNTuple<T, N>
+where
+    T: Ord + Clone,
+{
+    /// Returns this tuple's elements, cloned, in ascending sorted order.
+    ///
+    /// See [`UnorderedNTuple::into_sorted`] for the owned, non-cloning version.
+    pub fn sorted(&self) -> [T; N] {
+        let mut array = self.0.clone();
+        array.sort_unstable();
+        array
+    }
+
+    /// Compares two tuples for multiset equality in `O(N log N)` time, by sorting both tuples'
+    /// elements and comparing the sorted arrays.
+    ///
+    /// The default [`PartialEq`] impl only requires `T: PartialEq` and does an `O(N²)`
+    /// element-by-element matching, which is the only option when `T` isn't `Ord`. When `T: Ord +
+    /// Clone`, prefer this method instead for large `N`.
+    pub fn eq_sorted(&self, other: &Self) -> bool {
+        self.sorted() == other.sorted()
+    }
+}
+
+impl<T, const N: usize> Default for UnorderedNTuple<T, N>
+where
+    T: Default,
+{
+    fn default() -> Self {
+        Self(core::array::from_fn(|_| T::default()))
+    }
+}
+
+impl<T, const N: usize> core::ops::Deref for UnorderedNTuple<T, N> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        &self.0
+    }
+}
+
+impl<T, const N: usize> AsRef<[T]> for UnorderedNTuple<T, N> {
+    fn as_ref(&self) -> &[T] {
+        &self.0
+    }
+}
+
+impl<T, const N: usize> core::ops::Index<usize> for UnorderedNTuple<T, N> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        &self.0[index]
+    }
+}
+
+impl<T, const N: usize> IntoIterator for UnorderedNTuple<T, N> {
+    type Item = T;
+    type IntoIter = core::array::IntoIter<T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a UnorderedNTuple<T, N> {
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a mut UnorderedNTuple<T, N> {
+    type Item = &'a mut T;
+    type IntoIter = core::slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter_mut()
+    }
+}
+
+impl<T, const N: usize> UnorderedNTuple<T, N> {
+    /// Returns the number of elements in this tuple, i.e. `N`.
+    pub fn len(&self) -> usize {
+        N
+    }
+
+    /// Returns `true` if this tuple has no elements, i.e. `N == 0`.
+    pub fn is_empty(&self) -> bool {
+        N == 0
+    }
+}
+
+impl<T, const N: usize> UnorderedNTuple<T, N>
+where
+    T: PartialEq,
+{
+    /// Returns `true` if this tuple contains an element equal to `x`.
+    pub fn contains(&self, x: &T) -> bool {
+        self.0.iter().any(|item| item == x)
+    }
+
+    /// Returns the number of elements in this tuple that are equal to `x`.
+    pub fn count(&self, x: &T) -> usize {
+        self.0.iter().filter(|item| *item == x).count()
+    }
+}
+
 impl<T, const N: usize> PartialEq for UnorderedNTuple<T, N>
 where
     T: PartialEq,
@@ -97,20 +267,143 @@ where
     }
 }
 
-#[rustfmt::skip]
-if_feature!(
-    "std",
-    impl<T, const N: usize> Hash for UnorderedNTuple<T, N>
-    where
-        T: Hash + Ord + Clone,
-    {
-        fn hash<H: Hasher>(&self, state: &mut H) {
-            let mut sorted = self.0.clone();
-            sorted.sort();
-            Hash::hash_slice(&sorted, state);
+impl<T, const N: usize> PartialOrd for UnorderedNTuple<T, N>
+where
+    T: Ord + Clone,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Tuples are ordered by comparing their sorted, canonical arrays lexicographically.
+impl<T, const N: usize> Ord for UnorderedNTuple<T, N>
+where
+    T: Ord + Clone,
+{
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.sorted().cmp(&other.sorted())
+    }
+}
+
+impl<T, const N: usize> UnorderedNTuple<T, N> {
+    /// Returns an iterator over every distinct unordered N-tuple that can be drawn from `items`
+    /// without replacement (i.e. every N-combination of `items`).
+    ///
+    /// If `N` is greater than `items.len()`, the returned iterator yields nothing.
+    pub fn combinations(items: &[T]) -> Combinations<'_, T, N> {
+        Combinations::new(items)
+    }
+
+    /// Returns an iterator over every distinct unordered N-tuple that can be drawn from `items`
+    /// with replacement (i.e. every N-combination of `items`, allowing repeats).
+    ///
+    /// If `items` is empty and `N` is greater than zero, the returned iterator yields nothing.
+    pub fn combinations_with_replacement(items: &[T]) -> CombinationsWithReplacement<'_, T, N> {
+        CombinationsWithReplacement::new(items)
+    }
+}
+
+/// Iterator over every N-combination of a slice, returned by [`UnorderedNTuple::combinations`].
+pub struct Combinations<'a, T, const N: usize> {
+    items: &'a [T],
+    indices: [usize; N],
+    done: bool,
+}
+
+impl<'a, T, const N: usize> Combinations<'a, T, N> {
+    fn new(items: &'a [T]) -> Self {
+        let mut indices = [0; N];
+        for (index, slot) in indices.iter_mut().enumerate() {
+            *slot = index;
+        }
+        Self {
+            items,
+            indices,
+            done: N > items.len(),
         }
     }
-);
+}
+
+impl<'a, T, const N: usize> Iterator for Combinations<'a, T, N> {
+    type Item = UnorderedNTuple<&'a T, N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let items = self.items;
+        let current = UnorderedNTuple(core::array::from_fn(|i| &items[self.indices[i]]));
+
+        let m = items.len();
+        self.done = true;
+        for i in (0..N).rev() {
+            if self.indices[i] < m - N + i {
+                self.indices[i] += 1;
+                for j in (i + 1)..N {
+                    self.indices[j] = self.indices[i] + (j - i);
+                }
+                self.done = false;
+                break;
+            }
+        }
+        Some(current)
+    }
+}
+
+/// Iterator over every N-combination with replacement of a slice, returned by
+/// [`UnorderedNTuple::combinations_with_replacement`].
+pub struct CombinationsWithReplacement<'a, T, const N: usize> {
+    items: &'a [T],
+    indices: [usize; N],
+    done: bool,
+}
+
+impl<'a, T, const N: usize> CombinationsWithReplacement<'a, T, N> {
+    fn new(items: &'a [T]) -> Self {
+        Self {
+            items,
+            indices: [0; N],
+            done: N > 0 && items.is_empty(),
+        }
+    }
+}
+
+impl<'a, T, const N: usize> Iterator for CombinationsWithReplacement<'a, T, N> {
+    type Item = UnorderedNTuple<&'a T, N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let items = self.items;
+        let current = UnorderedNTuple(core::array::from_fn(|i| &items[self.indices[i]]));
+
+        let m = items.len();
+        self.done = true;
+        for i in (0..N).rev() {
+            if self.indices[i] < m - 1 {
+                self.indices[i] += 1;
+                let value = self.indices[i];
+                for j in (i + 1)..N {
+                    self.indices[j] = value;
+                }
+                self.done = false;
+                break;
+            }
+        }
+        Some(current)
+    }
+}
+
+impl<T, const N: usize> core::hash::Hash for UnorderedNTuple<T, N>
+where
+    T: core::hash::Hash + Ord + Clone,
+{
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        core::hash::Hash::hash_slice(&self.sorted(), state);
+    }
+}
 
 #[rustfmt::skip]
 if_feature!(
@@ -151,12 +444,15 @@ if_feature!(
         where
             S: SeqAccess<'de>,
         {
-            if access.size_hint() != Some(N) {
-                return Err(S::Error::custom("Wrong number of elements"));
-            }
-            let mut data: Vec<T> = Vec::new();
+            let mut data: Vec<T> = Vec::with_capacity(N);
             for _ in 0..N {
-                data.push(access.next_element()?.unwrap())
+                match access.next_element()? {
+                    Some(element) => data.push(element),
+                    None => return Err(S::Error::invalid_length(data.len(), &self)),
+                }
+            }
+            if access.next_element::<T>()?.is_some() {
+                return Err(S::Error::invalid_length(N + 1, &self));
             }
             Ok(UnorderedNTuple(
                 data.try_into().unwrap_or_else(|_| unreachable!()),
@@ -223,4 +519,185 @@ mod tests {
         }
         true
     }
+
+    /// Check that sorting is a canonical form: a tuple and its reversal compare the same whether
+    /// compared directly or by their sorted arrays
+    #[quickcheck]
+    fn check_sorted_is_canonical(a: usize, b: usize, c: usize) -> bool {
+        (UnorderedNTuple([a, b, c]) == UnorderedNTuple([c, b, a]))
+            == (UnorderedNTuple([a, b, c]).sorted() == UnorderedNTuple([c, b, a]).sorted())
+    }
+
+    /// Check that `Ord` agrees with comparing sorted arrays directly
+    #[quickcheck]
+    fn check_ord_matches_sorted(
+        a: usize,
+        b: usize,
+        c: usize,
+        d: usize,
+        e: usize,
+        f: usize,
+    ) -> bool {
+        UnorderedNTuple([a, b, c]).cmp(&UnorderedNTuple([d, e, f]))
+            == UnorderedNTuple([a, b, c])
+                .sorted()
+                .cmp(&UnorderedNTuple([d, e, f]).sorted())
+    }
+
+    /// Check that the default tuple is made up of default elements
+    #[test]
+    fn check_default() {
+        assert_eq!(
+            UnorderedNTuple::<usize, 3>::default(),
+            UnorderedNTuple([0, 0, 0])
+        );
+    }
+
+    /// Check that combinations of 2 out of 4 items yields every unordered pair exactly once
+    #[test]
+    fn check_combinations_pairs() {
+        let items = [1, 2, 3, 4];
+        let expected = [[1, 2], [1, 3], [1, 4], [2, 3], [2, 4], [3, 4]];
+        let mut seen = 0;
+        for (tuple, expected) in UnorderedNTuple::<i32, 2>::combinations(&items).zip(&expected) {
+            let [a, b] = tuple.into_sorted();
+            assert_eq!([*a, *b], *expected);
+            seen += 1;
+        }
+        assert_eq!(seen, expected.len());
+    }
+
+    /// Check that asking for more elements than are available yields no combinations
+    #[test]
+    fn check_combinations_too_large() {
+        let items = [1, 2];
+        assert_eq!(UnorderedNTuple::<i32, 3>::combinations(&items).count(), 0);
+    }
+
+    /// Check that combinations with replacement of 2 out of 3 items includes repeated elements
+    #[test]
+    fn check_combinations_with_replacement_pairs() {
+        let items = [1, 2, 3];
+        let expected = [[1, 1], [1, 2], [1, 3], [2, 2], [2, 3], [3, 3]];
+        let mut seen = 0;
+        for (tuple, expected) in
+            UnorderedNTuple::<i32, 2>::combinations_with_replacement(&items).zip(&expected)
+        {
+            let [a, b] = tuple.into_sorted();
+            assert_eq!([*a, *b], *expected);
+            seen += 1;
+        }
+        assert_eq!(seen, expected.len());
+    }
+
+    /// Check that combinations from an empty source yield nothing, unless N is 0
+    #[test]
+    fn check_combinations_empty_source() {
+        let items: [i32; 0] = [];
+        assert_eq!(UnorderedNTuple::<i32, 3>::combinations(&items).count(), 0);
+        assert_eq!(UnorderedNTuple::<i32, 0>::combinations(&items).count(), 1);
+        assert_eq!(
+            UnorderedNTuple::<i32, 3>::combinations_with_replacement(&items).count(),
+            0
+        );
+        assert_eq!(
+            UnorderedNTuple::<i32, 0>::combinations_with_replacement(&items).count(),
+            1
+        );
+    }
+
+    /// Check the slice-style accessors: `Deref`, `len`, `is_empty`, and `Index`
+    #[test]
+    fn check_slice_access() {
+        let tuple = UnorderedNTuple([1, 2, 3]);
+        assert_eq!(tuple.len(), 3);
+        assert!(!tuple.is_empty());
+        assert_eq!(&*tuple, &[1, 2, 3]);
+        assert_eq!(tuple[1], 2);
+        assert!(UnorderedNTuple::<i32, 0>::default().is_empty());
+    }
+
+    /// Check that the owned, borrowing, and mutably-borrowing iterators all visit every element
+    #[test]
+    fn check_iteration() {
+        let mut tuple = UnorderedNTuple([1, 2, 3]);
+        assert_eq!((&tuple).into_iter().sum::<i32>(), 6);
+        for element in &mut tuple {
+            *element *= 2;
+        }
+        assert_eq!(tuple.into_iter().sum::<i32>(), 12);
+    }
+
+    /// Check multiset-aware `contains`/`count`
+    #[test]
+    fn check_contains_and_count() {
+        let tuple = UnorderedNTuple([1, 2, 2, 3]);
+        assert!(tuple.contains(&2));
+        assert!(!tuple.contains(&5));
+        assert_eq!(tuple.count(&2), 2);
+        assert_eq!(tuple.count(&5), 0);
+    }
+
+    /// Check tuple conversions at a few representative arities, including the smallest and
+    /// largest generated (1 and 12)
+    #[test]
+    fn check_tuple_conversions() {
+        assert_eq!(UnorderedNTuple::from((1,)), UnorderedNTuple([1]));
+        assert_eq!(<(i32,)>::from(UnorderedNTuple([1])), (1,));
+
+        assert_eq!(UnorderedNTuple::from((1, 2)), UnorderedNTuple([1, 2]));
+        assert_eq!(<(i32, i32)>::from(UnorderedNTuple([1, 2])), (1, 2));
+
+        assert_eq!(UnorderedNTuple::from((1, 2, 3)), UnorderedNTuple([1, 2, 3]));
+        assert_eq!(
+            <(i32, i32, i32)>::from(UnorderedNTuple([1, 2, 3])),
+            (1, 2, 3)
+        );
+
+        let full = (1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12);
+        assert_eq!(
+            UnorderedNTuple::from(full),
+            UnorderedNTuple([1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12])
+        );
+        assert_eq!(
+            <(i32, i32, i32, i32, i32, i32, i32, i32, i32, i32, i32, i32)>::from(UnorderedNTuple(
+                [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12]
+            )),
+            full
+        );
+    }
+
+    /// Check that tuples which are equal (by multiset identity) always hash the same
+    #[cfg(feature = "std")]
+    #[quickcheck]
+    fn check_hash_consistent_with_eq(a: usize, b: usize, c: usize) -> bool {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        fn hash_of<T: Hash>(value: &T) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let x = UnorderedNTuple([a, b, c]);
+        let y = UnorderedNTuple([c, b, a]);
+        x != y || hash_of(&x) == hash_of(&y)
+    }
+
+    /// Check that the `O(N log N)` sorting-based `eq_sorted` agrees with the default `O(N²)`
+    /// `PartialEq` on arbitrary inputs
+    #[quickcheck]
+    fn check_eq_sorted_agrees_with_eq(
+        a: usize,
+        b: usize,
+        c: usize,
+        d: usize,
+        e: usize,
+        f: usize,
+    ) -> bool {
+        let x = UnorderedNTuple([a, b, c]);
+        let y = UnorderedNTuple([d, e, f]);
+        (x == y) == x.eq_sorted(&y)
+    }
 }